@@ -1,17 +1,188 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
 use log::debug;
 use pulldown_cmark::{Event, Options, Parser, Tag};
+use unicode_width::UnicodeWidthStr;
+
+/// Configuration for [`strip_markdown_with`].
+///
+/// `pulldown_cmark::Options` controls which Markdown extensions the parser
+/// recognizes; `StripOptions` controls both that and how the stripped
+/// output is rendered, the way `redcarpet` and rustdoc expose per-document
+/// rendering flags rather than a single fixed mode. Build one with
+/// [`StripOptions::new`] (equivalent to [`StripOptions::default`]) and
+/// adjust the toggles you need.
+// Each field is an independent document-rendering toggle, mirroring how
+// `pulldown_cmark::Options` itself is a flat set of flags; a bitflags-style
+// enum would be less ergonomic for callers than the builder methods below.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub struct StripOptions {
+    tables: bool,
+    footnotes: bool,
+    tasklists: bool,
+    strikethrough: bool,
+    smart_punctuation: bool,
+    keep_strikethrough_text: bool,
+    keep_link_urls: bool,
+    list_item_prefix: String,
+}
+
+impl Default for StripOptions {
+    fn default() -> Self {
+        StripOptions {
+            tables: false,
+            footnotes: false,
+            tasklists: false,
+            strikethrough: true,
+            smart_punctuation: false,
+            keep_strikethrough_text: false,
+            keep_link_urls: false,
+            list_item_prefix: "• ".to_string(),
+        }
+    }
+}
+
+impl StripOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable GFM tables (`pulldown_cmark::Options::ENABLE_TABLES`).
+    #[must_use]
+    pub fn tables(mut self, enable: bool) -> Self {
+        self.tables = enable;
+        self
+    }
+
+    /// Enable footnotes (`pulldown_cmark::Options::ENABLE_FOOTNOTES`).
+    #[must_use]
+    pub fn footnotes(mut self, enable: bool) -> Self {
+        self.footnotes = enable;
+        self
+    }
+
+    /// Enable GFM task lists (`pulldown_cmark::Options::ENABLE_TASKLISTS`).
+    #[must_use]
+    pub fn tasklists(mut self, enable: bool) -> Self {
+        self.tasklists = enable;
+        self
+    }
+
+    /// Enable strikethrough (`pulldown_cmark::Options::ENABLE_STRIKETHROUGH`).
+    #[must_use]
+    pub fn strikethrough(mut self, enable: bool) -> Self {
+        self.strikethrough = enable;
+        self
+    }
+
+    /// Normalize typography (`pulldown_cmark::Options::ENABLE_SMART_PUNCTUATION`):
+    /// straight quotes become curly quotes, `--`/`---` become en/em dashes,
+    /// and `...` becomes a single ellipsis character. Following
+    /// blackfriday/redcarpet, this is the "smartypants" feature. Since the
+    /// substitution happens in `pulldown_cmark` as it tokenizes `Event::Text`,
+    /// text inside code spans and code blocks (which arrive as `Event::Code`
+    /// or untouched `Event::Text` under a `Tag::CodeBlock`) is left verbatim.
+    #[must_use]
+    pub fn smart_punctuation(mut self, enable: bool) -> Self {
+        self.smart_punctuation = enable;
+        self
+    }
+
+    /// Keep the text inside `~~strikethrough~~` instead of dropping it.
+    #[must_use]
+    pub fn keep_strikethrough_text(mut self, keep: bool) -> Self {
+        self.keep_strikethrough_text = keep;
+        self
+    }
+
+    /// Render links as `text (url)` and images as `alt (url)` instead of
+    /// dropping the destination, collapsing to just the url when the
+    /// visible text already is the destination (e.g. `<https://example.com>`
+    /// autolinks and inline links whose text is the url). This only applies
+    /// to links pulldown-cmark actually parses as a `Tag::Link`; a bracketed
+    /// string with no matching reference definition, like `[url]` on its
+    /// own, has no link to retain a destination for and is emitted as the
+    /// literal text `[url]`, same as with this option off.
+    #[must_use]
+    pub fn keep_link_urls(mut self, keep: bool) -> Self {
+        self.keep_link_urls = keep;
+        self
+    }
+
+    /// The string written before each list item, `"• "` by default.
+    #[must_use]
+    pub fn list_item_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.list_item_prefix = prefix.into();
+        self
+    }
+
+    fn pulldown_options(&self) -> Options {
+        let mut options = Options::empty();
+        if self.strikethrough {
+            options.insert(Options::ENABLE_STRIKETHROUGH);
+        }
+        if self.tables {
+            options.insert(Options::ENABLE_TABLES);
+        }
+        if self.footnotes {
+            options.insert(Options::ENABLE_FOOTNOTES);
+        }
+        if self.tasklists {
+            options.insert(Options::ENABLE_TASKLISTS);
+        }
+        if self.smart_punctuation {
+            options.insert(Options::ENABLE_SMART_PUNCTUATION);
+        }
+        options
+    }
+}
 
+/// Strip Markdown down to plain text using the default [`StripOptions`].
 #[must_use]
 pub fn strip_markdown(markdown: &str) -> String {
-    // GFM tables and tasks lists are not enabled.
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
+    strip_markdown_with(markdown, &StripOptions::default())
+}
 
-    let parser = Parser::new_ext(&markdown, options);
+/// Strip Markdown down to plain text, honoring the given [`StripOptions`].
+#[must_use]
+pub fn strip_markdown_with(markdown: &str, options: &StripOptions) -> String {
+    let parser = Parser::new_ext(markdown, options.pulldown_options());
     let mut tags_stack = Vec::new();
     let mut buffer = String::new();
+    // Footnote definitions are rendered out of line, so their text is
+    // accumulated separately and only stitched back in at the very end.
+    let mut footnote_defs: HashMap<String, String> = HashMap::new();
+    let mut footnote_order: Vec<String> = Vec::new();
+    let mut current_footnote: Option<String> = None;
+    // Table rows/cells are accumulated structurally so the whole table can
+    // be laid out as aligned columns once `End(Tag::Table)` is reached.
+    let mut table_rows: Vec<Vec<String>> = Vec::new();
+    let mut current_row: Vec<String> = Vec::new();
+    let mut current_cell: Option<String> = None;
+    // While inside a link/image whose URL is being retained, its visible
+    // text is buffered separately so it can be combined with the
+    // destination once the tag closes.
+    let mut current_link: Option<(String, String)> = None;
+
+    // Shorthand for `active_buffer(...)` against the locals above; expands
+    // inline at each call site rather than borrowing them all at once, so
+    // the calls below stay one-liners without fighting the borrow checker.
+    macro_rules! buf {
+        () => {
+            active_buffer(
+                &mut buffer,
+                &mut footnote_defs,
+                current_footnote.as_deref(),
+                &mut current_cell,
+                &mut current_link,
+            )
+        };
+    }
 
     // For each event we push into the buffer to produce the 'stripped' version.
     for event in parser {
@@ -19,38 +190,269 @@ pub fn strip_markdown(markdown: &str) -> String {
         match event {
             // The start and end events don't contain the text inside the tag. That's handled by the `Event::Text` arm.
             Event::Start(tag) => {
-                start_tag(&tag, &mut buffer);
+                match &tag {
+                    Tag::FootnoteDefinition(label) => current_footnote = Some(label.to_string()),
+                    Tag::Table(_) => table_rows.clear(),
+                    Tag::TableHead | Tag::TableRow => current_row = Vec::new(),
+                    Tag::TableCell => current_cell = Some(String::new()),
+                    Tag::Link(_, dest, _) | Tag::Image(_, dest, _)
+                        if options.keep_link_urls =>
+                    {
+                        current_link = Some((dest.to_string(), String::new()));
+                    }
+                    _ => start_tag(&tag, buf!(), options),
+                }
                 tags_stack.push(tag);
             }
             Event::End(tag) => {
-                end_tag(&tag, &mut buffer);
+                match &tag {
+                    Tag::FootnoteDefinition(_) => current_footnote = None,
+                    Tag::TableCell => {
+                        if let Some(cell) = current_cell.take() {
+                            current_row.push(cell.trim().to_string());
+                        }
+                    }
+                    Tag::TableHead | Tag::TableRow => {
+                        table_rows.push(std::mem::take(&mut current_row));
+                    }
+                    Tag::Table(_) => {
+                        buffer.push_str(&render_table(&table_rows));
+                        buffer.push_str("\n\n");
+                        table_rows.clear();
+                    }
+                    Tag::Link(_, _, _) | Tag::Image(_, _, _) if options.keep_link_urls => {
+                        if let Some((dest, text)) = current_link.take() {
+                            let text = text.trim();
+                            let formatted = if text.is_empty() || text == dest {
+                                dest
+                            } else {
+                                format!("{text} ({dest})")
+                            };
+                            buf!().push_str(&formatted);
+                        }
+                    }
+                    _ => end_tag(&tag, buf!()),
+                }
                 tags_stack.pop();
             }
             Event::Text(content) => {
-                if !tags_stack.iter().any(is_strikethrough) {
-                    buffer.push_str(&content)
+                if options.keep_strikethrough_text || !tags_stack.iter().any(is_strikethrough) {
+                    buf!().push_str(&content);
                 }
             }
-            Event::Code(content) => buffer.push_str(&content),
-            Event::SoftBreak => buffer.push(' '),
+            Event::Code(content) => buf!().push_str(&content),
+            Event::SoftBreak => buf!().push(' '),
+            Event::HardBreak => buf!().push('\n'),
+            Event::Rule => buf!().push_str("----------\n\n"),
+            Event::TaskListMarker(checked) => {
+                buf!().push_str(if checked { "[x] " } else { "[ ] " });
+            }
+            Event::FootnoteReference(label) => {
+                if options.keep_strikethrough_text || !tags_stack.iter().any(is_strikethrough) {
+                    let label = label.to_string();
+                    let number = footnote_order
+                        .iter()
+                        .position(|l| l == &label)
+                        .unwrap_or_else(|| {
+                            footnote_order.push(label.clone());
+                            footnote_order.len() - 1
+                        })
+                        + 1;
+                    let _ = write!(buf!(), "[{number}]");
+                }
+            }
+            Event::Html(html) => buf!().push_str(&strip_html_tags(&html)),
+        }
+    }
+
+    let mut result = buffer.trim().to_string();
+    append_footnote_definitions(&mut result, &footnote_order, &footnote_defs);
+    result
+}
+
+/// Append the collected footnote definitions, in the order they were first
+/// referenced, after the main body text.
+fn append_footnote_definitions(
+    result: &mut String,
+    footnote_order: &[String],
+    footnote_defs: &HashMap<String, String>,
+) {
+    if footnote_order.is_empty() {
+        return;
+    }
+    result.push_str("\n\n");
+    for (i, label) in footnote_order.iter().enumerate() {
+        let number = i + 1;
+        let text = footnote_defs.get(label).map_or("", String::as_str).trim();
+        let _ = writeln!(result, "[{number}] {text}");
+    }
+    let trimmed_len = result.trim_end().len();
+    result.truncate(trimmed_len);
+}
+
+/// Produce a single-line, length-bounded plain-text preview of `markdown`.
+///
+/// This is the `short_markdown_summary` idea from rustdoc: paragraph breaks
+/// collapse to a single space, headings, code blocks, and images are
+/// skipped entirely, and the walk stops as soon as `max_len` *characters*
+/// (not bytes) have been accumulated at an event boundary, so a word is
+/// never cut mid-`Text`. An ellipsis is appended when the preview was
+/// truncated. Handy for link previews, meta descriptions, or search
+/// snippets.
+#[must_use]
+pub fn markdown_summary(markdown: &str, max_len: usize) -> String {
+    let parser = Parser::new(markdown);
+    let mut tags_stack: Vec<Tag> = Vec::new();
+    let mut buffer = String::new();
+    let mut truncated = false;
+
+    for event in parser {
+        let skip = tags_stack
+            .iter()
+            .any(|tag| matches!(tag, Tag::Heading(_) | Tag::CodeBlock(_) | Tag::Image(..)));
+        // The budget is spent and more text follows: the preview is truncated.
+        if !skip
+            && matches!(event, Event::Text(_) | Event::Code(_) | Event::SoftBreak)
+            && buffer.chars().count() >= max_len
+        {
+            truncated = true;
+            break;
+        }
+        match event {
+            Event::Start(tag) => {
+                tags_stack.push(tag);
+                continue;
+            }
+            Event::End(Tag::Paragraph) => {
+                tags_stack.pop();
+                if !buffer.is_empty() && !buffer.ends_with(' ') {
+                    buffer.push(' ');
+                }
+                continue;
+            }
+            Event::End(_) => {
+                tags_stack.pop();
+                continue;
+            }
+            Event::Text(content) | Event::Code(content) => {
+                if skip {
+                    continue;
+                }
+                buffer.push_str(&content);
+            }
+            Event::SoftBreak => {
+                if skip {
+                    continue;
+                }
+                buffer.push(' ');
+            }
+            _ => continue,
+        }
+    }
+
+    let mut result = buffer.trim().to_string();
+    if truncated {
+        let trimmed =
+            result.trim_end_matches(|c: char| c.is_whitespace() || c.is_ascii_punctuation());
+        result = format!("{}…", trimmed);
+    }
+    result
+}
+
+/// The buffer that events should currently be written to: the link/image
+/// whose visible text is being collected, if any, otherwise the table cell
+/// being parsed, if any, otherwise the footnote definition being parsed, if
+/// any, otherwise the main output buffer.
+fn active_buffer<'a>(
+    buffer: &'a mut String,
+    footnote_defs: &'a mut HashMap<String, String>,
+    current_footnote: Option<&str>,
+    current_cell: &'a mut Option<String>,
+    current_link: &'a mut Option<(String, String)>,
+) -> &'a mut String {
+    if let Some((_, text)) = current_link {
+        return text;
+    }
+    if let Some(cell) = current_cell {
+        return cell;
+    }
+    match current_footnote {
+        Some(label) => footnote_defs.entry(label.to_string()).or_default(),
+        None => buffer,
+    }
+}
+
+/// Lay out a parsed GFM table as space-padded plain-text columns, with a
+/// dashed separator line under the header row. Ragged rows and empty cells
+/// are padded out to the widest row's column count.
+fn render_table(rows: &[Vec<String>]) -> String {
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut widths = vec![0; column_count];
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(UnicodeWidthStr::width(cell.as_str()));
+        }
+    }
+
+    let render_row = |row: &[String]| -> String {
+        (0..column_count)
+            .map(|i| {
+                let cell = row.get(i).map_or("", String::as_str);
+                let padding = widths[i].saturating_sub(UnicodeWidthStr::width(cell));
+                format!("{cell}{}", " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-|-");
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    lines.push(render_row(&rows[0]));
+    lines.push(separator);
+    for row in &rows[1..] {
+        lines.push(render_row(row));
+    }
+    lines.join("\n")
+}
+
+/// Strip `<tag>`-style markup from a raw HTML fragment, keeping its text.
+fn strip_html_tags(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
             _ => (),
         }
     }
-    buffer.trim().to_string()
+    result
 }
 
-fn start_tag(tag: &Tag, buffer: &mut String) {
+fn start_tag(tag: &Tag, buffer: &mut String, options: &StripOptions) {
     match tag {
-        Tag::Link(_, _, title) | Tag::Image(_, _, title) => buffer.push_str(&title),
-        Tag::Item => buffer.push_str("• "),
+        Tag::Link(_, _, title) | Tag::Image(_, _, title) => buffer.push_str(title),
+        Tag::Item => buffer.push_str(&options.list_item_prefix),
         _ => (),
     }
 }
 
 fn end_tag(tag: &Tag, buffer: &mut String) {
     match tag {
-        Tag::Paragraph | Tag::Table(_) | Tag::Heading(_) | Tag::List(_) => buffer.push_str("\n\n"),
-        Tag::CodeBlock(_) | Tag::TableHead | Tag::TableRow | Tag::Item => buffer.push('\n'),
+        Tag::Paragraph | Tag::Heading(_) | Tag::List(_) => buffer.push_str("\n\n"),
+        Tag::CodeBlock(_) | Tag::Item => buffer.push('\n'),
         _ => (),
     }
 }
@@ -117,6 +519,14 @@ End paragraph.";
         assert_eq!(strip_markdown(markdown), expected);
     }
 
+    #[test]
+    fn strikethrough_kept() {
+        let markdown = r#"This was ~~erased~~ deleted."#;
+        let expected = "This was erased deleted.";
+        let options = StripOptions::new().keep_strikethrough_text(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
     #[test]
     fn mixed_list() {
         let markdown = r#"
@@ -146,6 +556,18 @@ End paragraph.";
         assert_eq!(strip_markdown(markdown), expected);
     }
 
+    #[test]
+    fn custom_list_item_prefix() {
+        let markdown = r#"
+* alpha
+* beta
+"#;
+        let expected = r#"- alpha
+- beta"#;
+        let options = StripOptions::new().list_item_prefix("- ");
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
     #[test]
     fn list_with_header() {
         let markdown = r#"# Title
@@ -166,12 +588,32 @@ End paragraph.";
         assert_eq!(strip_markdown(markdown), expected)
     }
 
-    #[ignore]
     #[test]
     fn link_with_itself() {
-        let markdown = "Go to [https://www.google.com].";
+        let markdown = "Go to <https://www.google.com>.";
         let expected = "Go to https://www.google.com.";
-        assert_eq!(strip_markdown(markdown), expected)
+        let options = StripOptions::new().keep_link_urls(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected)
+    }
+
+    #[test]
+    fn bare_bracketed_url_is_not_a_link() {
+        // `[url]` with no matching reference definition isn't a link at all
+        // in CommonMark (pulldown-cmark never emits a `Tag::Link` for it),
+        // so there's no destination for `keep_link_urls` to retain here; the
+        // brackets pass through as plain text either way.
+        let markdown = "Go to [https://www.google.com].";
+        let expected = "Go to [https://www.google.com].";
+        let options = StripOptions::new().keep_link_urls(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected)
+    }
+
+    #[test]
+    fn link_with_url_retained() {
+        let markdown = "I'm an [inline-style link](https://www.google.com).";
+        let expected = "I'm an inline-style link (https://www.google.com).";
+        let options = StripOptions::new().keep_link_urls(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected)
     }
 
     #[test]
@@ -221,6 +663,149 @@ End paragraph.";
         assert_eq!(strip_markdown(markdown), expected);
     }
 
+    #[test]
+    fn thematic_break() {
+        let markdown = r#"Before.
+
+---
+
+After."#;
+        let expected = "Before.
+
+----------
+
+After.";
+        assert_eq!(strip_markdown(markdown), expected);
+    }
+
+    #[test]
+    fn hard_break() {
+        let markdown = "Line one.  \nLine two.";
+        let expected = "Line one.\nLine two.";
+        assert_eq!(strip_markdown(markdown), expected);
+    }
+
+    #[test]
+    fn task_list() {
+        let markdown = r#"
+- [x] Done
+- [ ] Not done
+"#;
+        let options = StripOptions::new().tasklists(true);
+        let expected = r#"• [x] Done
+• [ ] Not done"#;
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn footnotes() {
+        let markdown = r#"Here's a claim.[^1]
+
+[^1]: The citation for that claim."#;
+        let options = StripOptions::new().footnotes(true);
+        let expected = r#"Here's a claim.[1]
+
+[1] The citation for that claim."#;
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn inline_html_is_stripped_but_text_kept() {
+        let markdown = "This is <strong>bold</strong> text.";
+        let expected = "This is bold text.";
+        assert_eq!(strip_markdown(markdown), expected);
+    }
+
+    #[test]
+    fn summary_short_text_is_returned_whole() {
+        let markdown = "Hello world.";
+        assert_eq!(markdown_summary(markdown, 100), "Hello world.");
+    }
+
+    #[test]
+    fn summary_truncates_at_char_budget() {
+        let markdown = "Paragraph one is here.
+
+Paragraph two follows it.";
+        assert_eq!(
+            markdown_summary(markdown, 10),
+            "Paragraph one is here…"
+        );
+    }
+
+    #[test]
+    fn summary_skips_headings_and_code_blocks() {
+        let markdown = r#"# Title
+
+```rust
+fn skipped() {}
+```
+
+The real summary text."#;
+        assert_eq!(markdown_summary(markdown, 100), "The real summary text.");
+    }
+
+    #[test]
+    fn summary_skips_images() {
+        let markdown = "Text before ![alt text](image.png) text after.";
+        assert_eq!(
+            markdown_summary(markdown, 100),
+            "Text before  text after."
+        );
+    }
+
+    #[test]
+    fn table() {
+        let markdown = r#"| Name | Age |
+| --- | --- |
+| Alice | 30 |
+| Bob | 7 |
+"#;
+        let options = StripOptions::new().tables(true);
+        let expected = r#"Name  | Age
+------|----
+Alice | 30
+Bob   | 7"#;
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn table_ragged_row() {
+        let markdown = r#"| A | B | C |
+| --- | --- | --- |
+| 1 | 2 |
+"#;
+        let options = StripOptions::new().tables(true);
+        let expected = r#"A | B | C
+--|---|--
+1 | 2 |"#;
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn smart_punctuation_quotes() {
+        let markdown = r#""Hello," she said, "it's a nice day.""#;
+        let expected = "“Hello,” she said, “it’s a nice day.”";
+        let options = StripOptions::new().smart_punctuation(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn smart_punctuation_dashes_and_ellipsis() {
+        let markdown = "pages 10--20 -- an em dash --- and then... silence.";
+        let expected = "pages 10–20 – an em dash — and then… silence.";
+        let options = StripOptions::new().smart_punctuation(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
+    #[test]
+    fn smart_punctuation_leaves_code_spans_verbatim() {
+        let markdown = "Use `a -- b` literally, but not -- this.";
+        let expected = "Use a -- b literally, but not – this.";
+        let options = StripOptions::new().smart_punctuation(true);
+        assert_eq!(strip_markdown_with(markdown, &options), expected);
+    }
+
     #[test]
     fn paragraphs() {
         let markdown = r#"Paragraph 1.